@@ -0,0 +1,218 @@
+use crate::datasource::{DataSource, FetchError, Rows};
+use crate::schema::SchemaVersion;
+use data_table::DataRow;
+use diwata_intel::{TableName, Window};
+use sauron::html::attributes::*;
+use sauron::html::events::*;
+use sauron::html::*;
+use sauron::{Cmd, Component, Node};
+use wasm_bindgen_futures::spawn_local;
+
+/// Number of rows requested per page from the [`DataSource`].
+pub const PAGE_SIZE: usize = 50;
+
+/// How close (in pixels) the grid must be scrolled to its bottom before the
+/// next page is requested.
+const SCROLL_THRESHOLD: f64 = 120.0;
+
+pub enum Msg {
+    BrowserResized(i32, i32),
+    /// Emitted by the data grid as it scrolls, carrying the geometry needed to
+    /// decide whether the next page should be loaded.
+    GridScrolled {
+        scroll_top: f64,
+        scroll_height: f64,
+        client_height: f64,
+    },
+    /// A page requested from the [`DataSource`] resolved.
+    PageLoaded(Result<Rows, FetchError>),
+}
+
+pub struct App {
+    windows: Vec<Window>,
+    width: i32,
+    height: i32,
+    /// Rows accumulated so far across the pages that have been loaded.
+    rows: Vec<DataRow>,
+    /// Offset of the next page to request.
+    offset: usize,
+    /// Whether a page request is currently in flight.
+    loading: bool,
+    /// Cleared once a short page signals the end of the result set.
+    has_more: bool,
+    /// The source the grid pulls rows from.
+    data_source: Option<Box<dyn DataSource>>,
+    /// A fatal error (failed fetch or schema mismatch) to surface instead of
+    /// the grid.
+    error: Option<String>,
+}
+
+impl App {
+    pub fn new(windows: Vec<Window>, width: i32, height: i32) -> Self {
+        App {
+            windows,
+            width,
+            height,
+            rows: vec![],
+            offset: 0,
+            loading: false,
+            has_more: true,
+            data_source: None,
+            error: None,
+        }
+    }
+
+    /// Mount an explicit error view for an incompatible schema version rather
+    /// than panicking through the renderer's `.expect(...)` calls.
+    pub fn with_schema_error(version: SchemaVersion, width: i32, height: i32) -> Self {
+        let mut app = App::new(vec![], width, height);
+        app.error = Some(format!(
+            "Incompatible schema version: host reported {:?}, this build supports {:?}.",
+            version,
+            SchemaVersion::current()
+        ));
+        app
+    }
+
+    /// Whether this compiled app can render data produced under schema `v`.
+    /// Exposed as an associated function so schema negotiation can be exercised
+    /// without a DOM.
+    pub fn supports_schema(v: &SchemaVersion) -> bool {
+        v.is_compatible_with(&SchemaVersion::current())
+    }
+
+    /// Install the source the grid pulls its rows from.
+    pub fn set_data_source(&mut self, data_source: Box<dyn DataSource>) {
+        self.data_source = Some(data_source);
+    }
+
+    /// The table backing the currently displayed window.
+    fn active_table(&self) -> TableName {
+        match self.windows.first() {
+            Some(window) => window.main_tab.table_name.clone(),
+            None => TableName::from("bazaar.product"),
+        }
+    }
+
+    /// Request a single page starting at `offset`, dispatching the outcome back
+    /// as [`Msg::PageLoaded`].
+    fn fetch_page(&self, offset: usize) -> Cmd<Self, Msg> {
+        match self.data_source {
+            Some(ref data_source) => {
+                let future = data_source.fetch_rows_async(&self.active_table(), offset, PAGE_SIZE);
+                Cmd::new(move |program| {
+                    let program = program.clone();
+                    spawn_local(async move {
+                        let result = future.await;
+                        program.dispatch(Msg::PageLoaded(result));
+                    });
+                })
+            }
+            None => Cmd::none(),
+        }
+    }
+
+    /// Request the next page if one is warranted, flipping `loading` so
+    /// concurrent scroll events don't stack duplicate requests.
+    fn load_next_page(&mut self) -> Cmd<Self, Msg> {
+        if self.loading || !self.has_more || self.error.is_some() {
+            return Cmd::none();
+        }
+        self.loading = true;
+        self.fetch_page(self.offset)
+    }
+}
+
+impl Component<Msg> for App {
+    /// Pull the first page as soon as the window opens.
+    fn init(&mut self) -> Cmd<Self, Msg> {
+        self.load_next_page()
+    }
+
+    fn update(&mut self, msg: Msg) -> Cmd<Self, Msg> {
+        match msg {
+            Msg::BrowserResized(width, height) => {
+                self.width = width;
+                self.height = height;
+                Cmd::none()
+            }
+            Msg::GridScrolled {
+                scroll_top,
+                scroll_height,
+                client_height,
+            } => {
+                let remaining = scroll_height - (scroll_top + client_height);
+                if remaining <= SCROLL_THRESHOLD {
+                    self.load_next_page()
+                } else {
+                    Cmd::none()
+                }
+            }
+            Msg::PageLoaded(Ok(rows)) => {
+                // A short page means we have reached the end of the result set.
+                self.has_more = rows.len() == PAGE_SIZE;
+                self.rows.extend(rows);
+                self.offset += PAGE_SIZE;
+                self.loading = false;
+                Cmd::none()
+            }
+            Msg::PageLoaded(Err(err)) => {
+                self.loading = false;
+                self.error = Some(err.to_string());
+                Cmd::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div(
+            [class("web-app")],
+            [match self.error {
+                Some(ref message) => self.error_view(message),
+                None => self.grid_view(),
+            }],
+        )
+    }
+}
+
+impl App {
+    fn error_view(&self, message: &str) -> Node<Msg> {
+        div([class("error-view")], [text(message)])
+    }
+
+    fn grid_view(&self) -> Node<Msg> {
+        div(
+            [
+                class("data-grid"),
+                on_scroll(|se| Msg::GridScrolled {
+                    scroll_top: se.scroll_top,
+                    scroll_height: se.scroll_height,
+                    client_height: se.client_height,
+                }),
+            ],
+            self.rows
+                .iter()
+                .map(|row| {
+                    div(
+                        [class("data-row")],
+                        row.values
+                            .iter()
+                            .map(|value| {
+                                div([class("data-cell")], [text(cell_text(value))])
+                            })
+                            .collect::<Vec<Node<Msg>>>(),
+                    )
+                })
+                .collect::<Vec<Node<Msg>>>(),
+        )
+    }
+}
+
+/// Render a single cell value to its display text, `Nil` becoming an empty
+/// cell to match the text renderer in `dao`.
+fn cell_text(value: &data_table::Value) -> String {
+    match *value {
+        data_table::Value::Nil => String::new(),
+        ref value => format!("{}", value),
+    }
+}
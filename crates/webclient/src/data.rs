@@ -0,0 +1,20 @@
+use data_table::{DataRow, Value};
+
+/// Number of sample rows handed out by [`make_sample_window_data`]. Kept larger
+/// than a single page so the scroll-driven pagination has something to walk
+/// through in the demo build.
+const SAMPLE_ROW_COUNT: usize = 200;
+
+/// Build an in-memory set of sample rows for the main tab, used by
+/// `SampleDataSource` and tests in place of a live endpoint.
+pub fn make_sample_window_data() -> Vec<DataRow> {
+    (0..SAMPLE_ROW_COUNT)
+        .map(|n| {
+            DataRow::new(vec![
+                Value::Int(n as i32),
+                Value::Text(format!("product {}", n)),
+                Value::Double(n as f64 * 1.5),
+            ])
+        })
+        .collect()
+}
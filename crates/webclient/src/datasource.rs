@@ -0,0 +1,179 @@
+use data_table::DataRow;
+use diwata_intel::TableName;
+use std::future::Future;
+use std::pin::Pin;
+
+/// A page of rows for a single tab, as returned by a [`DataSource`].
+pub type Rows = Vec<DataRow>;
+
+/// Error surfaced when a page of rows could not be fetched.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchError {
+    /// The endpoint refused or could not serve the request.
+    Unreachable(String),
+    /// The payload could not be decoded into rows.
+    Decode(String),
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            FetchError::Unreachable(ref msg) => write!(f, "data source unreachable: {}", msg),
+            FetchError::Decode(ref msg) => write!(f, "could not decode rows: {}", msg),
+        }
+    }
+}
+
+/// Recommended number of times an implementation of
+/// [`DataSource::fetch_rows_async`] should retry a transient
+/// [`FetchError::Unreachable`] before surfacing it to the UI.
+pub const MAX_RETRIES: usize = 3;
+
+/// A boxed future — what [`DataSource::fetch_rows_async`] returns so the
+/// trait stays object-safe behind `Box<dyn DataSource>`.
+pub type RowsFuture = Pin<Box<dyn Future<Output = Result<Rows, FetchError>>>>;
+
+/// Run `attempt` and retry it on a transient [`FetchError::Unreachable`], up to
+/// [`MAX_RETRIES`] times, before surfacing the error. Any other error returns
+/// immediately. Async [`DataSource`] implementations call this from their
+/// future with a closure that issues a fresh request each time, giving the
+/// bounded-retry contract a single implementation.
+pub fn retry<F>(mut attempt: F) -> Result<Rows, FetchError>
+where
+    F: FnMut() -> Result<Rows, FetchError>,
+{
+    let mut tries = 0;
+    loop {
+        match attempt() {
+            Err(FetchError::Unreachable(_)) if tries < MAX_RETRIES => {
+                tries += 1;
+            }
+            other => return other,
+        }
+    }
+}
+
+/// Abstraction over where the data grid pulls its rows from.
+///
+/// Modeled on the split between a blocking and a non-blocking client: the
+/// synchronous [`fetch_rows`](DataSource::fetch_rows) backs in-memory fixtures
+/// and tests, while the asynchronous
+/// [`fetch_rows_async`](DataSource::fetch_rows_async) is what the WASM app
+/// `dispatch`es pages from against a live endpoint.
+pub trait DataSource {
+    /// Fetch `limit` rows of `tab` starting at `offset`.
+    fn fetch_rows(&self, tab: &TableName, offset: usize, limit: usize) -> Result<Rows, FetchError>;
+
+    /// Asynchronous counterpart of [`fetch_rows`](DataSource::fetch_rows).
+    ///
+    /// This is a required method rather than a default wrapper over
+    /// [`fetch_rows`], because retrying a deterministic in-process call can
+    /// never change its outcome. A live implementation issues a genuine
+    /// request through [`retry`], so that on a transient
+    /// [`FetchError::Unreachable`] it retries up to [`MAX_RETRIES`] times
+    /// before yielding the error; on any other error it yields immediately.
+    fn fetch_rows_async(&self, tab: &TableName, offset: usize, limit: usize) -> RowsFuture;
+}
+
+/// In-memory [`DataSource`] backed by the bundled sample fixtures, keeping the
+/// grid populated without a live endpoint (used by tests and the demo build).
+pub struct SampleDataSource;
+
+impl DataSource for SampleDataSource {
+    fn fetch_rows(
+        &self,
+        _tab: &TableName,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Rows, FetchError> {
+        let all = crate::data::make_sample_window_data();
+        Ok(all.into_iter().skip(offset).take(limit).collect())
+    }
+
+    /// The sample source is in-memory and infallible, so the page is ready
+    /// immediately with no request to retry.
+    fn fetch_rows_async(&self, tab: &TableName, offset: usize, limit: usize) -> RowsFuture {
+        let result = self.fetch_rows(tab, offset, limit);
+        Box::pin(async move { result })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diwata_intel::TableName;
+    use std::cell::Cell;
+
+    /// A source that reports `Unreachable` for its first `failures` calls and
+    /// then succeeds, used to drive the retry contract.
+    struct FlakyDataSource {
+        failures: Cell<usize>,
+    }
+
+    impl DataSource for FlakyDataSource {
+        fn fetch_rows(&self, _tab: &TableName, _offset: usize, _limit: usize) -> Result<Rows, FetchError> {
+            let remaining = self.failures.get();
+            if remaining > 0 {
+                self.failures.set(remaining - 1);
+                Err(FetchError::Unreachable("transient".into()))
+            } else {
+                Ok(vec![])
+            }
+        }
+
+        fn fetch_rows_async(&self, tab: &TableName, offset: usize, limit: usize) -> RowsFuture {
+            let result = retry(|| self.fetch_rows(tab, offset, limit));
+            Box::pin(async move { result })
+        }
+    }
+
+    #[test]
+    fn retry_succeeds_within_budget() {
+        let source = FlakyDataSource {
+            failures: Cell::new(MAX_RETRIES),
+        };
+        let tab = TableName::from("bazaar.product");
+        let result = retry(|| source.fetch_rows(&tab, 0, 10));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn retry_surfaces_after_budget() {
+        let attempts = Cell::new(0usize);
+        let result = retry(|| {
+            attempts.set(attempts.get() + 1);
+            Err(FetchError::Unreachable("down".into()))
+        });
+        assert!(match result {
+            Err(FetchError::Unreachable(_)) => true,
+            _ => false,
+        });
+        // the initial attempt plus MAX_RETRIES retries
+        assert_eq!(MAX_RETRIES + 1, attempts.get());
+    }
+
+    #[test]
+    fn retry_does_not_retry_other_errors() {
+        let attempts = Cell::new(0usize);
+        let result = retry(|| {
+            attempts.set(attempts.get() + 1);
+            Err(FetchError::Decode("bad".into()))
+        });
+        assert!(match result {
+            Err(FetchError::Decode(_)) => true,
+            _ => false,
+        });
+        assert_eq!(1, attempts.get());
+    }
+
+    #[test]
+    fn sample_pagination_skip_take() {
+        let source = SampleDataSource;
+        let tab = TableName::from("bazaar.product");
+        let first = source.fetch_rows(&tab, 0, 50).unwrap();
+        assert_eq!(50, first.len());
+        // a short final page once the fixtures run out
+        let last = source.fetch_rows(&tab, 190, 50).unwrap();
+        assert_eq!(10, last.len());
+    }
+}
@@ -13,6 +13,10 @@ use wasm_bindgen::{self, prelude::*, JsCast};
 
 mod app;
 mod data;
+mod datasource;
+mod schema;
+
+use schema::{Negotiation, SchemaVersion};
 
 // When the `wee_alloc` feature is enabled, use `wee_alloc` as the global
 // allocator.
@@ -25,40 +29,66 @@ pub fn initialize(initial_state: &str) {
     #[cfg(feature = "console_error_panic_hook")]
     console_error_panic_hook::set_once();
     sauron::log!("initial state: {}", initial_state);
-    let root_node = sauron::document()
-        .get_element_by_id("web-app")
-        .expect("Unable to get hold of root-node");
+    let root_node = match sauron::document().get_element_by_id("web-app") {
+        Some(node) => node,
+        None => {
+            sauron::log!("Unable to get hold of root-node");
+            return;
+        }
+    };
+    let (window_width, window_height) = get_window_size();
+
+    // Negotiate the schema version the host advertises against the versions
+    // this binary was compiled for. A missing descriptor means an older host,
+    // which we tolerate; an incompatible window schema mounts an error view
+    // rather than letting the renderer mis-render newer data.
+    match SchemaVersion::from_initial_state(initial_state) {
+        Negotiation::Absent => {}
+        Negotiation::Present(version) => {
+            if !App::supports_schema(&version) {
+                sauron::log!(
+                    "incompatible schema version: host {:?}, supported {:?}",
+                    version,
+                    SchemaVersion::current()
+                );
+                let app = App::with_schema_error(version, window_width, window_height);
+                Program::new_replace_mount(app, &root_node);
+                return;
+            }
+        }
+        Negotiation::Malformed => {
+            sauron::log!("malformed schema descriptor in initial state");
+            let app = App::with_schema_error(
+                SchemaVersion {
+                    app_version: 0,
+                    window_schema_version: 0,
+                },
+                window_width,
+                window_height,
+            );
+            Program::new_replace_mount(app, &root_node);
+            return;
+        }
+    }
+
     let windows: Vec<Window> = vec![
         sample_window("Window1"),
         sample_window("Window2"),
         sample_window("Window3"),
     ];
-    let (window_width, window_height) = get_window_size();
     let mut app = App::new(windows, window_width, window_height);
-    app.set_window_data(0, crate::data::make_sample_window_data());
+    // Back the grid with a data source rather than eagerly loading the whole
+    // sample set; the app requests the first page when the window opens and
+    // further pages as the grid scrolls.
+    app.set_data_source(Box::new(crate::datasource::SampleDataSource));
     let program = Program::new_replace_mount(app, &root_node);
     setup_global_listeners(program);
 }
 
 fn setup_global_listeners(program: Rc<Program<App, Msg>>) {
-    setup_tick_listener(&program);
     setup_window_resize_listener(&program);
 }
 
-fn setup_tick_listener(program: &Rc<Program<App, Msg>>) {
-    let program_clone = Rc::clone(program);
-    let clock: Closure<Fn()> = Closure::wrap(Box::new(move || {
-        program_clone.dispatch(app::Msg::Tick);
-    }));
-    sauron::window()
-        .set_interval_with_callback_and_timeout_and_arguments_0(
-            clock.as_ref().unchecked_ref(),
-            3000,
-        )
-        .expect("Unable to start interval");
-    clock.forget();
-}
-
 fn setup_window_resize_listener(program: &Rc<Program<App, Msg>>) {
     let program_clone = Rc::clone(program);
     let resize_callback: Closure<Fn(web_sys::Event)> =
@@ -0,0 +1,131 @@
+use serde::Deserialize;
+
+/// Version descriptor carried in the host-provided initial state so the
+/// frontend can detect when it was built against a different window/field
+/// schema than the backend is serving.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct SchemaVersion {
+    pub app_version: u16,
+    pub window_schema_version: u16,
+}
+
+/// Outcome of reading the schema descriptor out of the host's initial state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Negotiation {
+    /// No descriptor present — an older host. The caller proceeds.
+    Absent,
+    /// A descriptor is present.
+    Present(SchemaVersion),
+    /// A `schema_version` descriptor is present but could not be parsed.
+    /// Treated as incompatible so the caller mounts the error view rather than
+    /// guessing.
+    Malformed,
+}
+
+/// The application version this binary was compiled against.
+pub const APP_VERSION: u16 = 1;
+/// The window/field schema version this binary understands. A mismatch here is
+/// what forces the error view, since it governs how data is rendered.
+pub const WINDOW_SCHEMA_VERSION: u16 = 1;
+
+impl SchemaVersion {
+    /// The versions this compiled app supports.
+    pub fn current() -> SchemaVersion {
+        SchemaVersion {
+            app_version: APP_VERSION,
+            window_schema_version: WINDOW_SCHEMA_VERSION,
+        }
+    }
+
+    /// Read the schema descriptor out of the host's initial state, carried as
+    /// a named `schema_version` sub-field rather than the whole payload.
+    ///
+    /// Anything without a `schema_version` field — an empty string, a
+    /// non-JSON blob, or a JSON object that simply omits it — is treated as
+    /// [`Absent`](Negotiation::Absent), matching the baseline behaviour where
+    /// `initialize` never parsed the state at all. Only a `schema_version`
+    /// that is present but does not deserialize is
+    /// [`Malformed`](Negotiation::Malformed).
+    pub fn from_initial_state(initial_state: &str) -> Negotiation {
+        let state: serde_json::Value = match serde_json::from_str(initial_state) {
+            Ok(state) => state,
+            Err(_) => return Negotiation::Absent,
+        };
+        match state.get("schema_version") {
+            None => Negotiation::Absent,
+            Some(descriptor) => match serde_json::from_value(descriptor.clone()) {
+                Ok(version) => Negotiation::Present(version),
+                Err(_) => Negotiation::Malformed,
+            },
+        }
+    }
+
+    /// Whether `self` (the host's schema) is compatible with this compiled app.
+    ///
+    /// The window schema version must match exactly; the application version
+    /// may differ (a minor difference is tolerated and rendered as usual).
+    pub fn is_compatible_with(&self, supported: &SchemaVersion) -> bool {
+        self.window_schema_version == supported.window_schema_version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compatible_minor_app_version() {
+        let host = SchemaVersion {
+            app_version: APP_VERSION + 5,
+            window_schema_version: WINDOW_SCHEMA_VERSION,
+        };
+        assert!(host.is_compatible_with(&SchemaVersion::current()));
+    }
+
+    #[test]
+    fn incompatible_window_schema() {
+        let host = SchemaVersion {
+            app_version: APP_VERSION,
+            window_schema_version: WINDOW_SCHEMA_VERSION + 1,
+        };
+        assert!(!host.is_compatible_with(&SchemaVersion::current()));
+    }
+
+    #[test]
+    fn absent_on_empty_or_non_json() {
+        assert_eq!(Negotiation::Absent, SchemaVersion::from_initial_state(""));
+        assert_eq!(
+            Negotiation::Absent,
+            SchemaVersion::from_initial_state("not json at all")
+        );
+    }
+
+    #[test]
+    fn absent_when_field_missing() {
+        assert_eq!(
+            Negotiation::Absent,
+            SchemaVersion::from_initial_state(r#"{"windows": []}"#)
+        );
+    }
+
+    #[test]
+    fn present_when_descriptor_parses() {
+        let state = r#"{"schema_version": {"app_version": 2, "window_schema_version": 1}}"#;
+        assert_eq!(
+            Negotiation::Present(SchemaVersion {
+                app_version: 2,
+                window_schema_version: 1,
+            }),
+            SchemaVersion::from_initial_state(state)
+        );
+    }
+
+    #[test]
+    fn malformed_when_descriptor_unparseable() {
+        let state = r#"{"schema_version": {"app_version": "oops"}}"#;
+        assert_eq!(
+            Negotiation::Malformed,
+            SchemaVersion::from_initial_state(state)
+        );
+    }
+}
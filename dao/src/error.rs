@@ -0,0 +1,15 @@
+/// Errors that can occur converting a `Value` into a concrete Rust type.
+#[derive(Debug, PartialEq)]
+pub enum ConvertError {
+    /// The stored variant can not be converted into the requested type.
+    /// Carries the found type name and the expected type name.
+    NotSupported(String, String),
+    /// The stored value is of a compatible variant but does not fit the
+    /// narrower destination type.
+    OutOfRange {
+        target: String,
+        value: String,
+        min: String,
+        max: String,
+    },
+}
@@ -1,6 +1,7 @@
 #![deny(warnings)]
 #![feature(try_from)]
 
+extern crate bigdecimal;
 extern crate chrono;
 extern crate serde;
 #[macro_use]
@@ -16,6 +17,7 @@ pub use dao::FromDao;
 pub use dao::ToDao;
 pub use table::Table;
 pub use table::ToTable;
+pub use render::TableStyle;
 
 
 mod dao;
@@ -23,3 +25,4 @@ mod value;
 mod error;
 mod rows;
 mod table;
+mod render;
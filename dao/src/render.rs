@@ -0,0 +1,304 @@
+use rows::Rows;
+use table::Table;
+use value::Value;
+
+/// Default maximum rendered width of a single cell before it is truncated with
+/// an ellipsis.
+pub const DEFAULT_MAX_WIDTH: usize = 40;
+
+/// The grid style produced by [`Table::render_text`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TableStyle {
+    /// Unicode box-drawing grid, suitable for a terminal or a log.
+    Plain,
+    /// GitHub-flavored markdown table, suitable for copy-paste export.
+    Markdown,
+}
+
+impl Table {
+    /// Render this table's `rows` into a formatted text grid using the bundled
+    /// default cell width.
+    pub fn render_text(&self, rows: &Rows, style: TableStyle) -> String {
+        self.render_text_with_width(rows, style, DEFAULT_MAX_WIDTH)
+    }
+
+    /// Render `rows` into a text grid, truncating any cell wider than
+    /// `max_width` with an ellipsis. A `max_width` of 0 disables truncation.
+    pub fn render_text_with_width(
+        &self,
+        rows: &Rows,
+        style: TableStyle,
+        max_width: usize,
+    ) -> String {
+        let headers: Vec<String> = rows.columns.clone();
+        let body: Vec<Vec<String>> = rows
+            .data
+            .iter()
+            .map(|row| row.iter().map(|v| render_cell(v, max_width)).collect())
+            .collect();
+
+        let widths = column_widths(&headers, &body);
+        let aligns = column_alignments(&rows.data);
+
+        match style {
+            TableStyle::Plain => render_plain(&headers, &body, &widths, &aligns),
+            TableStyle::Markdown => render_markdown(&headers, &body, &widths, &aligns),
+        }
+    }
+}
+
+/// Whether a column is rendered right-aligned (numeric) or left-aligned.
+#[derive(Clone, Copy, PartialEq)]
+enum Align {
+    Left,
+    Right,
+}
+
+fn is_numeric(value: &Value) -> bool {
+    match *value {
+        Value::Tinyint(_)
+        | Value::Smallint(_)
+        | Value::Int(_)
+        | Value::Bigint(_)
+        | Value::Float(_)
+        | Value::Double(_) => true,
+        _ => false,
+    }
+}
+
+/// Render a single value into its cell text, `Nil` becoming an empty cell and
+/// over-long text being truncated with an ellipsis.
+fn render_cell(value: &Value, max_width: usize) -> String {
+    let text = match *value {
+        Value::Nil => String::new(),
+        Value::Bool(v) => v.to_string(),
+        Value::Tinyint(v) => v.to_string(),
+        Value::Smallint(v) => v.to_string(),
+        Value::Int(v) => v.to_string(),
+        Value::Bigint(v) => v.to_string(),
+        Value::Float(v) => v.to_string(),
+        Value::Double(v) => v.to_string(),
+        Value::Blob(ref v) => format!("{} bytes", v.len()),
+        Value::Text(ref v) => v.clone(),
+        Value::Str(v) => v.to_string(),
+        Value::Uuid(ref v) => v.to_string(),
+        Value::Date(ref v) => v.to_string(),
+        Value::Timestamp(ref v) => v.to_string(),
+        Value::Json(ref v) => v.to_string(),
+        Value::Numeric(ref v) => v.to_string(),
+        Value::Array(ref v) => {
+            let parts: Vec<String> = v.iter().map(|e| render_cell(e, max_width)).collect();
+            format!("[{}]", parts.join(", "))
+        }
+    };
+    truncate(&text, max_width)
+}
+
+fn truncate(text: &str, max_width: usize) -> String {
+    if max_width == 0 || text.chars().count() <= max_width {
+        return text.to_string();
+    }
+    if max_width == 1 {
+        return "…".to_string();
+    }
+    let mut out: String = text.chars().take(max_width - 1).collect();
+    out.push('…');
+    out
+}
+
+fn column_widths(headers: &[String], body: &[Vec<String>]) -> Vec<usize> {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in body {
+        for (i, cell) in row.iter().enumerate() {
+            let len = cell.chars().count();
+            if i < widths.len() {
+                if len > widths[i] {
+                    widths[i] = len;
+                }
+            } else {
+                widths.push(len);
+            }
+        }
+    }
+    widths
+}
+
+/// A column is right-aligned when its first non-`Nil` value is numeric.
+fn column_alignments(data: &[Vec<Value>]) -> Vec<Align> {
+    let ncols = data.iter().map(|r| r.len()).max().unwrap_or(0);
+    (0..ncols)
+        .map(|col| {
+            let numeric = data
+                .iter()
+                .filter_map(|row| row.get(col))
+                .find(|v| **v != Value::Nil)
+                .map(is_numeric)
+                .unwrap_or(false);
+            if numeric {
+                Align::Right
+            } else {
+                Align::Left
+            }
+        })
+        .collect()
+}
+
+fn pad(cell: &str, width: usize, align: Align) -> String {
+    let len = cell.chars().count();
+    let fill = width.saturating_sub(len);
+    match align {
+        Align::Left => format!("{}{}", cell, " ".repeat(fill)),
+        Align::Right => format!("{}{}", " ".repeat(fill), cell),
+    }
+}
+
+fn align_at(aligns: &[Align], col: usize) -> Align {
+    aligns.get(col).copied().unwrap_or(Align::Left)
+}
+
+fn render_plain(
+    headers: &[String],
+    body: &[Vec<String>],
+    widths: &[usize],
+    aligns: &[Align],
+) -> String {
+    let border = |left: &str, mid: &str, right: &str| -> String {
+        let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+        format!("{}{}{}", left, segments.join(mid), right)
+    };
+    let format_row = |cells: &[String]| -> String {
+        let rendered: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                let empty = String::new();
+                let cell = cells.get(i).unwrap_or(&empty);
+                format!(" {} ", pad(cell, *w, align_at(aligns, i)))
+            })
+            .collect();
+        format!("│{}│", rendered.join("│"))
+    };
+
+    let mut out = String::new();
+    out.push_str(&border("┌", "┬", "┐"));
+    out.push('\n');
+    out.push_str(&format_row(headers));
+    out.push('\n');
+    out.push_str(&border("├", "┼", "┤"));
+    out.push('\n');
+    for row in body {
+        out.push_str(&format_row(row));
+        out.push('\n');
+    }
+    out.push_str(&border("└", "┴", "┘"));
+    out
+}
+
+fn render_markdown(
+    headers: &[String],
+    body: &[Vec<String>],
+    widths: &[usize],
+    aligns: &[Align],
+) -> String {
+    let format_row = |cells: &[String]| -> String {
+        let rendered: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                let empty = String::new();
+                let cell = cells.get(i).unwrap_or(&empty);
+                pad(cell, *w, align_at(aligns, i))
+            })
+            .collect();
+        format!("| {} |", rendered.join(" | "))
+    };
+    let separator: Vec<String> = widths
+        .iter()
+        .enumerate()
+        .map(|(i, w)| match align_at(aligns, i) {
+            Align::Right => format!("{}:", "-".repeat(w + 1)),
+            Align::Left => "-".repeat(w + 2),
+        })
+        .collect();
+
+    let mut out = String::new();
+    out.push_str(&format_row(headers));
+    out.push('\n');
+    out.push_str(&format!("|{}|", separator.join("|")));
+    for row in body {
+        out.push('\n');
+        out.push_str(&format_row(row));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use value::Value;
+
+    fn sample() -> (Vec<String>, Vec<Vec<Value>>) {
+        let headers = vec!["id".to_string(), "name".to_string()];
+        let data = vec![
+            vec![Value::Int(1), Value::Text("ab".to_string())],
+            vec![Value::Int(20), Value::Nil],
+        ];
+        (headers, data)
+    }
+
+    fn body_of(data: &[Vec<Value>]) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|row| row.iter().map(|v| render_cell(v, DEFAULT_MAX_WIDTH)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn nil_renders_empty_cell() {
+        assert_eq!("", render_cell(&Value::Nil, DEFAULT_MAX_WIDTH));
+    }
+
+    #[test]
+    fn numeric_right_text_left() {
+        let (_, data) = sample();
+        let aligns = column_alignments(&data);
+        assert_eq!(Align::Right, aligns[0]);
+        assert_eq!(Align::Left, aligns[1]);
+    }
+
+    #[test]
+    fn truncates_multibyte_with_ellipsis() {
+        let truncated = truncate("héllo wörld", 5);
+        assert_eq!(5, truncated.chars().count());
+        assert!(truncated.ends_with('…'));
+        assert_eq!("héll…", truncated);
+    }
+
+    #[test]
+    fn markdown_output() {
+        let (headers, data) = sample();
+        let body = body_of(&data);
+        let widths = column_widths(&headers, &body);
+        let aligns = column_alignments(&data);
+        let expected = "\
+| id | name |
+|---:|------|
+|  1 | ab   |
+| 20 |      |";
+        assert_eq!(expected, render_markdown(&headers, &body, &widths, &aligns));
+    }
+
+    #[test]
+    fn plain_output() {
+        let (headers, data) = sample();
+        let body = body_of(&data);
+        let widths = column_widths(&headers, &body);
+        let aligns = column_alignments(&data);
+        let out = render_plain(&headers, &body, &widths, &aligns);
+        assert!(out.starts_with('┌'));
+        assert!(out.ends_with('┘'));
+        assert!(out.contains("│ id │ name │"));
+        // numeric column is right-aligned, text column left-aligned
+        assert!(out.contains("│  1 │ ab   │"));
+        assert!(out.contains("│ 20 │      │"));
+    }
+}
@@ -2,6 +2,8 @@ use uuid::Uuid;
 use chrono::NaiveDate;
 use chrono::{DateTime, Utc};
 use std::convert::TryFrom;
+use bigdecimal::BigDecimal;
+use serde_json;
 use error::ConvertError;
 
 
@@ -28,6 +30,39 @@ pub enum Value {
     Uuid(Uuid),
     Date(NaiveDate),
     Timestamp(DateTime<Utc>),
+
+    // The payloads below are larger than the 32 byte budget, so they are boxed
+    // to keep `size_of::<Value>()` at 32.
+    Json(Box<serde_json::Value>),
+    Numeric(#[serde(with = "bigdecimal_serde")] Box<BigDecimal>),
+    Array(Box<Vec<Value>>),
+}
+
+/// Serde adapter for `Box<BigDecimal>` as a string, so round-tripping does not
+/// depend on the `bigdecimal/serde` feature being enabled.
+mod bigdecimal_serde {
+    use super::BigDecimal;
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::Serializer;
+    use std::str::FromStr;
+
+    #[allow(clippy::borrowed_box)]
+    pub fn serialize<S>(value: &Box<BigDecimal>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Box<BigDecimal>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        BigDecimal::from_str(&s)
+            .map(Box::new)
+            .map_err(D::Error::custom)
+    }
 }
 
 impl Value {
@@ -50,6 +85,9 @@ impl Value {
             Value::Uuid(_) => "Uuid",
             Value::Date(_) => "NaiveDate",
             Value::Timestamp(_) => "DateTime",
+            Value::Json(_) => "Json",
+            Value::Numeric(_) => "BigDecimal",
+            Value::Array(_) => "Array",
         }
     }
 }
@@ -99,6 +137,57 @@ impl_from!(Uuid, Uuid);
 impl_from!(NaiveDate, Date);
 impl_from!(DateTime<Utc>, Timestamp);
 
+macro_rules! impl_from_boxed {
+    ($ty:ty, $variant: ident) => {
+        /// Owned types, boxed into the variant
+        impl From<$ty> for Value {
+            fn from(f: $ty) -> Self{
+                Value::$variant(Box::new(f))
+            }
+        }
+
+        /// For borrowed types
+        impl<'a> From<&'a $ty> for Value {
+            fn from(f: &'a $ty) -> Self{
+                Value::$variant(Box::new(f.to_owned()))
+            }
+        }
+
+        /// for borrowed option types
+        impl<'a> From<&'a Option<$ty>> for Value {
+            fn from(f: &'a Option<$ty>) -> Self{
+                match *f{
+                    Some(ref f) => From::from(f),
+                    None => Value::Nil,
+                }
+            }
+        }
+    }
+}
+
+impl_from_boxed!(serde_json::Value, Json);
+impl_from_boxed!(BigDecimal, Numeric);
+impl_from_boxed!(Vec<Value>, Array);
+
+/// The `Option<T>` forwarding impl, shared by every `impl_tryfrom*` variant.
+macro_rules! impl_tryfrom_option {
+    ($ty: ty) => {
+        /// try from to Option<T>
+        impl<'a> TryFrom<&'a Value> for Option<$ty> {
+            type Error = ConvertError;
+
+            fn try_from(value: &'a Value) -> Result<Self, Self::Error> {
+                match *value {
+                    Value::Nil => Ok(None),
+                    _ => TryFrom::try_from(value).map(|v|Some(v)),
+                }
+            }
+        }
+    }
+}
+
+/// Exact conversions: the source variant already holds the destination type,
+/// so no range check is needed.
 macro_rules! impl_tryfrom {
     ($ty: ty, $ty_name: tt, $($variant: ident),*) => {
         /// try from to owned
@@ -114,27 +203,84 @@ macro_rules! impl_tryfrom {
             }
         }
 
-        /// try from to Option<T>
-        impl<'a> TryFrom<&'a Value> for Option<$ty> {
+        impl_tryfrom_option!($ty);
+    }
+}
+
+/// Integer conversions: accept any listed source variant, widening comparisons
+/// through `i128` and reporting an `OutOfRange` error when the stored value
+/// does not fit the narrower destination instead of silently truncating.
+macro_rules! impl_tryfrom_int {
+    ($ty: ty, $ty_name: tt, $($variant: ident),*) => {
+        /// try from to owned
+        impl<'a> TryFrom<&'a Value> for $ty {
             type Error = ConvertError;
 
             fn try_from(value: &'a Value) -> Result<Self, Self::Error> {
                 match *value {
-                    Value::Nil => Ok(None),
-                    _ => TryFrom::try_from(value).map(|v|Some(v)), 
+                    $(Value::$variant(v) => {
+                        let wide = v as i128;
+                        if wide < <$ty>::min_value() as i128 || wide > <$ty>::max_value() as i128 {
+                            return Err(ConvertError::OutOfRange {
+                                target: $ty_name.into(),
+                                value: v.to_string(),
+                                min: <$ty>::min_value().to_string(),
+                                max: <$ty>::max_value().to_string(),
+                            });
+                        }
+                        Ok(v as $ty)
+                    }
+                    )*
+                    _ => Err(ConvertError::NotSupported(value.get_type_name().to_string(), $ty_name.into())),
                 }
             }
         }
+
+        impl_tryfrom_option!($ty);
+    }
+}
+
+/// Float conversions: accept wider float variants, comparing in `f64` and
+/// reporting an `OutOfRange` error when the value is out of the destination's
+/// finite range.
+macro_rules! impl_tryfrom_float {
+    ($ty: ty, $ty_name: tt, $($variant: ident),*) => {
+        /// try from to owned
+        impl<'a> TryFrom<&'a Value> for $ty {
+            type Error = ConvertError;
+
+            fn try_from(value: &'a Value) -> Result<Self, Self::Error> {
+                match *value {
+                    $(Value::$variant(v) => {
+                        let wide = v as f64;
+                        if wide.is_finite()
+                            && (wide < -(<$ty>::MAX as f64) || wide > <$ty>::MAX as f64) {
+                            return Err(ConvertError::OutOfRange {
+                                target: $ty_name.into(),
+                                value: v.to_string(),
+                                min: (-<$ty>::MAX).to_string(),
+                                max: <$ty>::MAX.to_string(),
+                            });
+                        }
+                        Ok(v as $ty)
+                    }
+                    )*
+                    _ => Err(ConvertError::NotSupported(value.get_type_name().to_string(), $ty_name.into())),
+                }
+            }
+        }
+
+        impl_tryfrom_option!($ty);
     }
 }
 
 impl_tryfrom!(bool, "bool", Bool);
-impl_tryfrom!(i8, "i8", Tinyint);
-impl_tryfrom!(i16, "i16", Tinyint, Smallint);
-impl_tryfrom!(i32, "i32", Tinyint, Smallint, Int);
-impl_tryfrom!(i64, "i64", Tinyint, Smallint, Int, Bigint);
-impl_tryfrom!(f32, "f32", Float);
-impl_tryfrom!(f64, "f64", Float, Double);
+impl_tryfrom_int!(i8, "i8", Tinyint, Smallint, Int, Bigint);
+impl_tryfrom_int!(i16, "i16", Tinyint, Smallint, Int, Bigint);
+impl_tryfrom_int!(i32, "i32", Tinyint, Smallint, Int, Bigint);
+impl_tryfrom_int!(i64, "i64", Tinyint, Smallint, Int, Bigint);
+impl_tryfrom_float!(f32, "f32", Float, Double);
+impl_tryfrom_float!(f64, "f64", Float, Double);
 impl_tryfrom!(Vec<u8>, "Vec<u8>", Blob);
 impl_tryfrom!(String, "String", Text);
 impl_tryfrom!(&'static str, "&'static str", Str);
@@ -142,6 +288,56 @@ impl_tryfrom!(Uuid, "Uuid", Uuid);
 impl_tryfrom!(NaiveDate, "NaiveDate", Date);
 impl_tryfrom!(DateTime<Utc>, "DateTime<Utc>", Timestamp);
 
+/// Boxed payloads: unbox and clone on extraction.
+macro_rules! impl_tryfrom_boxed {
+    ($ty: ty, $ty_name: tt, $variant: ident) => {
+        /// try from to owned
+        impl<'a> TryFrom<&'a Value> for $ty {
+            type Error = ConvertError;
+
+            fn try_from(value: &'a Value) -> Result<Self, Self::Error> {
+                match *value {
+                    Value::$variant(ref v) => Ok((**v).to_owned()),
+                    _ => Err(ConvertError::NotSupported(value.get_type_name().to_string(), $ty_name.into())),
+                }
+            }
+        }
+
+        impl_tryfrom_option!($ty);
+    }
+}
+
+impl_tryfrom_boxed!(serde_json::Value, "Json", Json);
+impl_tryfrom_boxed!(BigDecimal, "BigDecimal", Numeric);
+
+/// Extract a `Value::Array` of homogeneous scalars into a `Vec<T>`, converting
+/// each element through its own `TryFrom<&Value>` impl.
+macro_rules! impl_tryfrom_array {
+    ($elem: ty, $ty_name: tt) => {
+        impl<'a> TryFrom<&'a Value> for Vec<$elem> {
+            type Error = ConvertError;
+
+            fn try_from(value: &'a Value) -> Result<Self, Self::Error> {
+                match *value {
+                    Value::Array(ref items) => {
+                        items.iter().map(|v| TryFrom::try_from(v)).collect()
+                    }
+                    _ => Err(ConvertError::NotSupported(value.get_type_name().to_string(), $ty_name.into())),
+                }
+            }
+        }
+    }
+}
+
+impl_tryfrom_array!(bool, "Vec<bool>");
+impl_tryfrom_array!(i8, "Vec<i8>");
+impl_tryfrom_array!(i16, "Vec<i16>");
+impl_tryfrom_array!(i32, "Vec<i32>");
+impl_tryfrom_array!(i64, "Vec<i64>");
+impl_tryfrom_array!(f32, "Vec<f32>");
+impl_tryfrom_array!(f64, "Vec<f64>");
+impl_tryfrom_array!(String, "Vec<String>");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -156,6 +352,22 @@ mod tests {
         assert_eq!(12, size_of::<DateTime<Utc>>());
         assert_eq!(4, size_of::<NaiveDate>());
         assert_eq!(16, size_of::<Uuid>());
+        // the richer payloads are boxed down to a single pointer so the 32
+        // byte budget above is preserved
+        assert_eq!(8, size_of::<Box<serde_json::Value>>());
+        assert_eq!(8, size_of::<Box<BigDecimal>>());
+        assert_eq!(8, size_of::<Box<Vec<Value>>>());
+    }
+
+    #[test]
+    fn array_into_vec() {
+        let v = Value::Array(Box::new(vec![
+            Value::Int(1),
+            Value::Int(2),
+            Value::Int(3),
+        ]));
+        let extracted: Vec<i32> = TryFrom::try_from(&v).unwrap();
+        assert_eq!(vec![1, 2, 3], extracted);
     }
 
     #[test]
@@ -172,4 +384,36 @@ mod tests {
         let _v5: Value = "hello world!".to_string().into();
         let _v6: Value = vec![1u8, 2, 255, 3].into();
     }
+
+    #[test]
+    fn narrowing_in_range() {
+        let v = Value::Bigint(5);
+        let n: i32 = TryFrom::try_from(&v).unwrap();
+        assert_eq!(5i32, n);
+    }
+
+    #[test]
+    fn narrowing_out_of_range() {
+        let v = Value::Bigint(5_000_000_000);
+        let res: Result<i32, _> = TryFrom::try_from(&v);
+        assert_eq!(
+            res,
+            Err(ConvertError::OutOfRange {
+                target: "i32".into(),
+                value: "5000000000".into(),
+                min: i32::min_value().to_string(),
+                max: i32::max_value().to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn not_supported_unchanged() {
+        let v = Value::Text("hello".to_string());
+        let res: Result<i32, _> = TryFrom::try_from(&v);
+        assert_eq!(
+            res,
+            Err(ConvertError::NotSupported("String".into(), "i32".into()))
+        );
+    }
 }